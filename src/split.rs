@@ -1,4 +1,5 @@
-use std::io::Error;
+use std::fmt;
+use std::io::{Error, IoSlice};
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
 use std::sync::Arc;
@@ -74,12 +75,16 @@ impl<R, W> RHalf<R, W> {
         Arc::ptr_eq(&self.k, &w.k)
     }
 
-    pub fn unsplit(self, w: WHalf<R, W>) -> Splittable<R, W> {
+    pub fn try_unsplit(self, w: WHalf<R, W>) -> Result<Splittable<R, W>, ReuniteError<R, W>> {
         if !self.is_pair_of(&w) {
-            panic!("not a pair");
+            return Err(ReuniteError { r: self, w });
         }
 
-        Splittable::new(self.r, w.w)
+        Ok(Splittable::new(self.r, w.w))
+    }
+
+    pub fn unsplit(self, w: WHalf<R, W>) -> Splittable<R, W> {
+        self.try_unsplit(w).unwrap_or_else(|_| panic!("not a pair"))
     }
 }
 
@@ -115,11 +120,37 @@ impl<R, W> WHalf<R, W> {
         r.is_pair_of(self)
     }
 
+    pub fn try_unsplit(self, r: RHalf<R, W>) -> Result<Splittable<R, W>, ReuniteError<R, W>> {
+        r.try_unsplit(self)
+    }
+
     pub fn unsplit(self, r: RHalf<R, W>) -> Splittable<R, W> {
         r.unsplit(self)
     }
 }
 
+/// Error returned by [`RHalf::try_unsplit`]/[`WHalf::try_unsplit`] when the two halves
+/// were not split from the same [`Splittable`]. The original halves are handed back
+/// intact so the caller can recover from the mismatch instead of losing them.
+pub struct ReuniteError<R, W> {
+    pub r: RHalf<R, W>,
+    pub w: WHalf<R, W>,
+}
+
+impl<R, W> fmt::Debug for ReuniteError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReuniteError").finish()
+    }
+}
+
+impl<R, W> fmt::Display for ReuniteError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tried to unsplit halves that are not a pair")
+    }
+}
+
+impl<R, W> std::error::Error for ReuniteError<R, W> {}
+
 impl<R, W> DerefMut for WHalf<R, W> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.w
@@ -146,6 +177,14 @@ impl<R: tokio::io::AsyncRead + Unpin, W: tokio::io::AsyncWrite + Unpin> tokio::i
     fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
         Pin::new(self.get_w_mut()).poll_shutdown(cx)
     }
+
+    fn poll_write_vectored(mut self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>]) -> Poll<Result<usize, Error>> {
+        Pin::new(self.get_w_mut()).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.get_w().is_write_vectored()
+    }
 }
 
 #[cfg(feature = "io")]
@@ -155,6 +194,164 @@ impl<R, W> tokio::io::AsyncRead for Splittable<R, W> where R: tokio::io::AsyncRe
     }
 }
 
+#[cfg(feature = "io")]
+impl<R: tokio::io::AsyncRead + Unpin, W> tokio::io::AsyncRead for RHalf<R, W> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().r).poll_read(cx, buf)
+    }
+}
+
+#[cfg(feature = "io")]
+impl<R, W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for WHalf<R, W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize, Error>> {
+        Pin::new(&mut self.get_mut().w).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().w).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().w).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>]) -> Poll<Result<usize, Error>> {
+        Pin::new(&mut self.get_mut().w).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.w.is_write_vectored()
+    }
+}
+
+#[cfg(feature = "io")]
+struct DuplexInner<T> {
+    stream: std::sync::Mutex<T>,
+}
+
+/// Read half of a single `AsyncRead + AsyncWrite` value split with [`split`].
+#[cfg(feature = "io")]
+pub struct ReadHalf<T> {
+    inner: Arc<DuplexInner<T>>,
+}
+
+/// Write half of a single `AsyncRead + AsyncWrite` value split with [`split`].
+#[cfg(feature = "io")]
+pub struct WriteHalf<T> {
+    inner: Arc<DuplexInner<T>>,
+}
+
+/// Splits a single value that is both an `AsyncRead` and an `AsyncWrite` into an owned
+/// [`ReadHalf`] and [`WriteHalf`] pair, mirroring `tokio::io::split`. The two halves share
+/// the stream behind a lock, so only one side touches it at a time.
+#[cfg(feature = "io")]
+pub fn split<T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin>(stream: T) -> (ReadHalf<T>, WriteHalf<T>) {
+    let inner = Arc::new(DuplexInner {
+        stream: std::sync::Mutex::new(stream),
+    });
+    (
+        ReadHalf { inner: inner.clone() },
+        WriteHalf { inner },
+    )
+}
+
+#[cfg(feature = "io")]
+impl<T> ReadHalf<T> {
+    pub fn is_pair_of(&self, w: &WriteHalf<T>) -> bool {
+        Arc::ptr_eq(&self.inner, &w.inner)
+    }
+
+    pub fn try_unsplit(self, w: WriteHalf<T>) -> Result<T, DuplexReuniteError<T>> {
+        if !self.is_pair_of(&w) {
+            return Err(DuplexReuniteError { r: self, w });
+        }
+
+        drop(w);
+        let inner = Arc::try_unwrap(self.inner).unwrap_or_else(|_| panic!("not a pair"));
+        Ok(inner.stream.into_inner().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub fn unsplit(self, w: WriteHalf<T>) -> T {
+        self.try_unsplit(w).unwrap_or_else(|_| panic!("not a pair"))
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> WriteHalf<T> {
+    pub fn is_pair_of(&self, r: &ReadHalf<T>) -> bool {
+        r.is_pair_of(self)
+    }
+
+    pub fn try_unsplit(self, r: ReadHalf<T>) -> Result<T, DuplexReuniteError<T>> {
+        r.try_unsplit(self)
+    }
+
+    pub fn unsplit(self, r: ReadHalf<T>) -> T {
+        r.unsplit(self)
+    }
+}
+
+/// Error returned by [`ReadHalf::try_unsplit`]/[`WriteHalf::try_unsplit`] when the two
+/// halves were not produced by the same call to [`split`]. The original halves are handed
+/// back intact so the caller can recover from the mismatch instead of losing the stream.
+#[cfg(feature = "io")]
+pub struct DuplexReuniteError<T> {
+    pub r: ReadHalf<T>,
+    pub w: WriteHalf<T>,
+}
+
+#[cfg(feature = "io")]
+impl<T> fmt::Debug for DuplexReuniteError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DuplexReuniteError").finish()
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> fmt::Display for DuplexReuniteError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tried to unsplit halves that are not a pair")
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> std::error::Error for DuplexReuniteError<T> {}
+
+#[cfg(feature = "io")]
+impl<T: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for ReadHalf<T> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> Poll<Result<(), Error>> {
+        let mut guard = self.inner.stream.lock().unwrap();
+        Pin::new(&mut *guard).poll_read(cx, buf)
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for WriteHalf<T> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize, Error>> {
+        let mut guard = self.inner.stream.lock().unwrap();
+        Pin::new(&mut *guard).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        let mut guard = self.inner.stream.lock().unwrap();
+        Pin::new(&mut *guard).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        let mut guard = self.inner.stream.lock().unwrap();
+        Pin::new(&mut *guard).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>]) -> Poll<Result<usize, Error>> {
+        let mut guard = self.inner.stream.lock().unwrap();
+        Pin::new(&mut *guard).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.stream.lock().unwrap().is_write_vectored()
+    }
+}
+
 #[cfg(test)]
 mod Test {
     #[test]
@@ -181,4 +378,124 @@ mod Test {
         let s1 = r1.unsplit(w1);
         let s2 = r2.unsplit(w2);
     }
+
+    #[test]
+    fn try_unsplit_mismatched_pair_returns_halves() {
+        pub struct TestRead {}
+
+        pub struct TestWrite {}
+
+        let s1 = super::Splittable::new(TestRead {}, TestWrite {});
+        let (r1, w1) = s1.split();
+        let s2 = super::Splittable::new(TestRead {}, TestWrite {});
+        let (r2, w2) = s2.split();
+
+        let err = r1.try_unsplit(w2).err().expect("mismatched pair must fail");
+
+        // the halves handed back are still the original ones, paired with their real partners
+        let _s1 = err.r.unsplit(w1);
+        let _s2 = r2.unsplit(err.w);
+    }
+
+    #[cfg(feature = "io")]
+    #[tokio::test]
+    async fn rhalf_whalf_poll_through_inner() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (client_r, mut server_r) = tokio::io::duplex(64);
+        let (client_w, mut server_w) = tokio::io::duplex(64);
+
+        let s = super::Splittable::new(client_r, client_w);
+        let (mut r, mut w) = s.split();
+
+        server_r.write_all(b"hello").await.unwrap();
+        let mut buf = [0u8; 5];
+        r.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"hello");
+
+        w.write_all(b"world").await.unwrap();
+        let mut buf = [0u8; 5];
+        server_w.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"world");
+    }
+
+    #[cfg(feature = "io")]
+    #[tokio::test]
+    async fn duplex_split_round_trips_and_reunites() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (a, mut b) = tokio::io::duplex(64);
+        let (mut r, mut w) = super::split(a);
+
+        b.write_all(b"ping").await.unwrap();
+        let mut buf = [0u8; 4];
+        r.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+
+        w.write_all(b"pong").await.unwrap();
+        let mut buf = [0u8; 4];
+        b.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong");
+
+        assert!(r.is_pair_of(&w));
+        let _a = r.unsplit(w);
+    }
+
+    #[cfg(feature = "io")]
+    #[tokio::test]
+    async fn duplex_try_unsplit_mismatched_pair_returns_halves() {
+        let (a1, _b1) = tokio::io::duplex(64);
+        let (a2, _b2) = tokio::io::duplex(64);
+
+        let (r1, w1) = super::split(a1);
+        let (r2, w2) = super::split(a2);
+
+        let err = r1.try_unsplit(w2).err().expect("mismatched pair must fail");
+
+        let _a1 = err.r.unsplit(w1);
+        let _a2 = r2.unsplit(err.w);
+    }
+
+    #[cfg(feature = "io")]
+    #[tokio::test]
+    async fn whalf_poll_write_vectored_delivers_full_payload() {
+        use std::io::IoSlice;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (client_r, _server_r) = tokio::io::duplex(64);
+        let (client_w, mut server_w) = tokio::io::duplex(64);
+
+        let s = super::Splittable::new(client_r, client_w);
+        let (_r, mut w) = s.split();
+
+        assert!(w.is_write_vectored());
+
+        let bufs = [IoSlice::new(b"hello "), IoSlice::new(b"world")];
+        w.write_vectored(&bufs).await.unwrap();
+        w.flush().await.unwrap();
+
+        let mut buf = [0u8; 11];
+        server_w.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"hello world");
+    }
+
+    #[cfg(feature = "io")]
+    #[tokio::test]
+    async fn duplex_writehalf_poll_write_vectored_delivers_full_payload() {
+        use std::io::IoSlice;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (a, mut b) = tokio::io::duplex(64);
+        let (_r, mut w) = super::split(a);
+
+        assert!(w.is_write_vectored());
+
+        let bufs = [IoSlice::new(b"foo "), IoSlice::new(b"bar")];
+        w.write_vectored(&bufs).await.unwrap();
+        w.flush().await.unwrap();
+
+        let mut buf = [0u8; 7];
+        b.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"foo bar");
+    }
 }
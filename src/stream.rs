@@ -0,0 +1,217 @@
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// Wraps a value that is both a `futures::Stream` and a `futures::Sink` so it can be torn
+/// into an owned receive half and send half, mirroring [`crate::split::split`] for the
+/// message-oriented side of the async ecosystem.
+pub struct SplittableStream<St> {
+    st: St,
+}
+
+impl<St> SplittableStream<St> {
+    pub fn new(st: St) -> Self {
+        Self { st }
+    }
+
+    pub fn split(self) -> (StreamHalf<St>, SinkHalf<St>) {
+        let inner = Arc::new(std::sync::Mutex::new(self.st));
+        (
+            StreamHalf { inner: inner.clone() },
+            SinkHalf { inner },
+        )
+    }
+
+    pub fn get_ref(&self) -> &St {
+        &self.st
+    }
+
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.st
+    }
+}
+
+impl<St> Deref for SplittableStream<St> {
+    type Target = St;
+    fn deref(&self) -> &Self::Target {
+        &self.st
+    }
+}
+
+impl<St> DerefMut for SplittableStream<St> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.st
+    }
+}
+
+/// Receive half produced by [`SplittableStream::split`].
+pub struct StreamHalf<St> {
+    inner: Arc<std::sync::Mutex<St>>,
+}
+
+/// Send half produced by [`SplittableStream::split`].
+pub struct SinkHalf<St> {
+    inner: Arc<std::sync::Mutex<St>>,
+}
+
+impl<St> StreamHalf<St> {
+    pub fn is_pair_of(&self, sink: &SinkHalf<St>) -> bool {
+        Arc::ptr_eq(&self.inner, &sink.inner)
+    }
+
+    pub fn try_unsplit(self, sink: SinkHalf<St>) -> Result<SplittableStream<St>, StreamReuniteError<St>> {
+        if !self.is_pair_of(&sink) {
+            return Err(StreamReuniteError { stream: self, sink });
+        }
+
+        drop(self.inner);
+        let inner = Arc::try_unwrap(sink.inner).unwrap_or_else(|_| panic!("not a pair"));
+        Ok(SplittableStream::new(inner.into_inner().unwrap_or_else(|e| e.into_inner())))
+    }
+
+    pub fn unsplit(self, sink: SinkHalf<St>) -> SplittableStream<St> {
+        self.try_unsplit(sink).unwrap_or_else(|_| panic!("not a pair"))
+    }
+}
+
+impl<St> SinkHalf<St> {
+    pub fn is_pair_of(&self, stream: &StreamHalf<St>) -> bool {
+        stream.is_pair_of(self)
+    }
+
+    pub fn try_unsplit(self, stream: StreamHalf<St>) -> Result<SplittableStream<St>, StreamReuniteError<St>> {
+        stream.try_unsplit(self)
+    }
+
+    pub fn unsplit(self, stream: StreamHalf<St>) -> SplittableStream<St> {
+        stream.unsplit(self)
+    }
+}
+
+/// Error returned by [`StreamHalf::try_unsplit`]/[`SinkHalf::try_unsplit`] when the two
+/// halves were not split from the same [`SplittableStream`]. The original halves are handed
+/// back intact so the caller can recover from the mismatch instead of losing them.
+pub struct StreamReuniteError<St> {
+    pub stream: StreamHalf<St>,
+    pub sink: SinkHalf<St>,
+}
+
+impl<St> std::fmt::Debug for StreamReuniteError<St> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamReuniteError").finish()
+    }
+}
+
+impl<St> std::fmt::Display for StreamReuniteError<St> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tried to unsplit halves that are not a pair")
+    }
+}
+
+impl<St> std::error::Error for StreamReuniteError<St> {}
+
+#[cfg(feature = "stream")]
+impl<St: futures::Stream + Unpin> futures::Stream for StreamHalf<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut guard = self.inner.lock().unwrap();
+        Pin::new(&mut *guard).poll_next(cx)
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<Item, St: futures::Sink<Item> + Unpin> futures::Sink<Item> for SinkHalf<St> {
+    type Error = St::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut guard = self.inner.lock().unwrap();
+        Pin::new(&mut *guard).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let mut guard = self.inner.lock().unwrap();
+        Pin::new(&mut *guard).start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut guard = self.inner.lock().unwrap();
+        Pin::new(&mut *guard).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut guard = self.inner.lock().unwrap();
+        Pin::new(&mut *guard).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod Test {
+    use std::collections::VecDeque;
+    use std::convert::Infallible;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    struct Channel {
+        items: VecDeque<i32>,
+    }
+
+    impl futures::Stream for Channel {
+        type Item = i32;
+
+        fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<i32>> {
+            Poll::Ready(self.get_mut().items.pop_front())
+        }
+    }
+
+    impl futures::Sink<i32> for Channel {
+        type Error = Infallible;
+
+        fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: i32) -> Result<(), Infallible> {
+            self.get_mut().items.push_back(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn streamhalf_sinkhalf_round_trip() {
+        use futures::{SinkExt, StreamExt};
+
+        let s = super::SplittableStream::new(Channel { items: VecDeque::new() });
+        let (mut stream, mut sink) = s.split();
+
+        sink.send(42).await.unwrap();
+        assert_eq!(stream.next().await, Some(42));
+
+        assert!(stream.is_pair_of(&sink));
+        let _s = stream.unsplit(sink);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn streamhalf_sinkhalf_try_unsplit_mismatched_pair_returns_halves() {
+        let s1 = super::SplittableStream::new(Channel { items: VecDeque::new() });
+        let (stream1, sink1) = s1.split();
+        let s2 = super::SplittableStream::new(Channel { items: VecDeque::new() });
+        let (stream2, sink2) = s2.split();
+
+        let err = stream1.try_unsplit(sink2).err().expect("mismatched pair must fail");
+
+        let _s1 = err.stream.unsplit(sink1);
+        let _s2 = stream2.unsplit(err.sink);
+    }
+}